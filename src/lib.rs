@@ -0,0 +1,31 @@
+//! Parse `/proc/[pid]/mountinfo` (and friends) and answer questions about
+//! the mount points of a process's mount namespace.
+
+#[cfg(unix)]
+extern crate libc;
+#[cfg(windows)]
+extern crate winapi;
+
+pub mod error;
+#[cfg(target_os = "linux")]
+pub mod mount;
+pub mod mntops;
+pub mod mount_point;
+pub mod process;
+pub mod query;
+pub mod tree;
+
+pub use error::{LineError, ParseError};
+pub use mntops::MntOps;
+#[cfg(target_os = "linux")]
+pub use mount::{unmount, Mount, MountMode, UnmountFlags};
+pub use mount_point::{mount_points, MountPoint};
+pub use process::{
+    parse_mountinfo, parse_mounts, proc_mountinfo, proc_mounts, self_mountinfo, self_mounts,
+    MountEntry, MountId, PeerGroup, Propagation,
+};
+pub use query::{
+    filter_by_filesystem, filter_by_filesystem_in, find_by_mount_point, find_by_mount_point_in,
+    is_source_mounted, is_source_mounted_in, is_target_mounted, is_target_mounted_in,
+};
+pub use tree::MountTree;
@@ -0,0 +1,90 @@
+use std::str::FromStr;
+
+use LineError;
+
+/// A single mount option, as found in the comma-separated option list of a
+/// `/proc/self/mountinfo` or `/proc/self/mounts` line.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum MntOps {
+    /// `rw` / `ro`
+    Write(bool),
+    /// `suid` / `nosuid`
+    Suid(bool),
+    /// `dev` / `nodev`
+    Dev(bool),
+    /// `exec` / `noexec`
+    Exec(bool),
+    /// `sync` / `async`
+    Sync(bool),
+    /// `mand` / `nomand`
+    Mandlock(bool),
+    /// `dirsync`
+    DirSync,
+    /// `atime` / `noatime`
+    ATime(bool),
+    /// `diratime` / `nodiratime`
+    DirATime(bool),
+    /// `relatime` / `norelatime`
+    RelAtime(bool),
+    /// `strictatime`
+    StrictATime,
+    /// `lazytime` / `nolazytime`
+    LazyTime(bool),
+    /// any option this crate doesn't interpret itself, kept verbatim so it
+    /// can still be round-tripped into a `mount(2)` `data` argument.
+    Extra(String),
+}
+
+impl FromStr for MntOps {
+    type Err = LineError;
+
+    fn from_str(s: &str) -> Result<MntOps, LineError> {
+        Ok(match s {
+            "rw" => MntOps::Write(true),
+            "ro" => MntOps::Write(false),
+            "suid" => MntOps::Suid(true),
+            "nosuid" => MntOps::Suid(false),
+            "dev" => MntOps::Dev(true),
+            "nodev" => MntOps::Dev(false),
+            "exec" => MntOps::Exec(true),
+            "noexec" => MntOps::Exec(false),
+            "sync" => MntOps::Sync(true),
+            "async" => MntOps::Sync(false),
+            "mand" => MntOps::Mandlock(true),
+            "nomand" => MntOps::Mandlock(false),
+            "dirsync" => MntOps::DirSync,
+            "atime" => MntOps::ATime(true),
+            "noatime" => MntOps::ATime(false),
+            "diratime" => MntOps::DirATime(true),
+            "nodiratime" => MntOps::DirATime(false),
+            "relatime" => MntOps::RelAtime(true),
+            "norelatime" => MntOps::RelAtime(false),
+            "strictatime" => MntOps::StrictATime,
+            "lazytime" => MntOps::LazyTime(true),
+            "nolazytime" => MntOps::LazyTime(false),
+            _ => MntOps::Extra(s.to_owned()),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_known_opts() {
+        assert_eq!("rw".parse(), Ok(MntOps::Write(true)));
+        assert_eq!("nosuid".parse(), Ok(MntOps::Suid(false)));
+        assert_eq!("nodev".parse(), Ok(MntOps::Dev(false)));
+        assert_eq!("noexec".parse(), Ok(MntOps::Exec(false)));
+        assert_eq!("relatime".parse(), Ok(MntOps::RelAtime(true)));
+    }
+
+    #[test]
+    fn test_parse_extra_opt() {
+        assert_eq!(
+            "data=ordered".parse(),
+            Ok(MntOps::Extra("data=ordered".to_owned()))
+        );
+    }
+}
@@ -0,0 +1,198 @@
+use std::error::Error;
+use std::ffi::CString;
+use std::io;
+use std::os::unix::ffi::OsStrExt;
+use std::path::Path;
+use std::ptr;
+
+use libc::{self, c_ulong};
+
+use mntops::MntOps;
+use Propagation;
+
+/// How a target should be (re)established by [`Mount::mount`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MountMode {
+    /// A regular, fresh mount of `filesystem` from `source` onto `target`.
+    Mount,
+    /// Bind an already-mounted `source` onto `target`, optionally pulling in
+    /// everything mounted under it (`MS_BIND[|MS_REC]`).
+    Bind {
+        /// also bind everything mounted below `source` (`MS_REC`).
+        recursive: bool,
+    },
+    /// Change the options of the mount already at `target` (`MS_REMOUNT`).
+    Remount,
+    /// Change only the propagation of the mount already at `target`
+    /// (`MS_SHARED`/`MS_SLAVE`/`MS_PRIVATE`/`MS_UNBINDABLE`).
+    Propagate(Propagation),
+}
+
+/// A builder for the `mount(2)` syscall, translating the same `MntOps`
+/// vocabulary used to parse `/proc/self/mountinfo` into the `MS_*` flags and
+/// `data` blob the kernel expects.
+#[derive(Clone, Debug)]
+pub struct Mount {
+    source: Option<CString>,
+    target: CString,
+    filesystem: Option<CString>,
+    mode: MountMode,
+    opts: Vec<MntOps>,
+}
+
+impl Mount {
+    /// Start building a mount onto `target`.
+    pub fn new<P: AsRef<Path>>(target: P) -> io::Result<Mount> {
+        Ok(Mount {
+            source: None,
+            target: path_to_cstring(target.as_ref())?,
+            filesystem: None,
+            mode: MountMode::Mount,
+            opts: vec![],
+        })
+    }
+
+    /// The device, directory or other source being mounted.
+    pub fn source<P: AsRef<Path>>(mut self, source: P) -> io::Result<Mount> {
+        self.source = Some(path_to_cstring(source.as_ref())?);
+        Ok(self)
+    }
+
+    /// The filesystem type, e.g. `"ext4"` or `"tmpfs"`.
+    pub fn filesystem(mut self, filesystem: &str) -> io::Result<Mount> {
+        self.filesystem = Some(CString::new(filesystem).map_err(invalid_input)?);
+        Ok(self)
+    }
+
+    /// The per-mount options, in the same vocabulary `parse_mountinfo` uses.
+    pub fn opts(mut self, opts: &[MntOps]) -> Mount {
+        self.opts = opts.to_vec();
+        self
+    }
+
+    /// How the target should be (re)established; defaults to `MountMode::Mount`.
+    pub fn mode(mut self, mode: MountMode) -> Mount {
+        self.mode = mode;
+        self
+    }
+
+    /// Perform the `mount(2)` call built up so far.
+    pub fn mount(self) -> io::Result<()> {
+        let (mut flags, data) = opts_to_flags_and_data(&self.opts);
+
+        flags |= match self.mode {
+            MountMode::Mount => 0,
+            MountMode::Bind { recursive: false } => libc::MS_BIND,
+            MountMode::Bind { recursive: true } => libc::MS_BIND | libc::MS_REC,
+            MountMode::Remount => libc::MS_REMOUNT,
+            MountMode::Propagate(Propagation::Shared(_)) => libc::MS_SHARED,
+            MountMode::Propagate(Propagation::Slave { .. }) => libc::MS_SLAVE,
+            MountMode::Propagate(Propagation::Private) => libc::MS_PRIVATE,
+            MountMode::Propagate(Propagation::Unbindable) => libc::MS_UNBINDABLE,
+        };
+
+        let data = CString::new(data).map_err(invalid_input)?;
+
+        let ret = unsafe {
+            libc::mount(
+                self.source
+                    .as_ref()
+                    .map(|s| s.as_ptr())
+                    .unwrap_or_else(ptr::null),
+                self.target.as_ptr(),
+                self.filesystem
+                    .as_ref()
+                    .map(|s| s.as_ptr())
+                    .unwrap_or_else(ptr::null),
+                flags as c_ulong,
+                if data.as_bytes().is_empty() {
+                    ptr::null()
+                } else {
+                    data.as_ptr() as *const _
+                },
+            )
+        };
+
+        if ret == 0 {
+            Ok(())
+        } else {
+            Err(io::Error::last_os_error())
+        }
+    }
+}
+
+/// Translate the non-`Extra` options into their `MS_*` flag, and join the
+/// `Extra` ones into the comma-separated `data` string.
+fn opts_to_flags_and_data(opts: &[MntOps]) -> (libc::c_ulong, String) {
+    let mut flags = 0;
+    let mut extra = vec![];
+
+    for opt in opts {
+        match *opt {
+            MntOps::Write(false) => flags |= libc::MS_RDONLY,
+            MntOps::Suid(false) => flags |= libc::MS_NOSUID,
+            MntOps::Dev(false) => flags |= libc::MS_NODEV,
+            MntOps::Exec(false) => flags |= libc::MS_NOEXEC,
+            MntOps::Sync(true) => flags |= libc::MS_SYNCHRONOUS,
+            MntOps::Mandlock(true) => flags |= libc::MS_MANDLOCK,
+            MntOps::DirSync => flags |= libc::MS_DIRSYNC,
+            MntOps::ATime(false) => flags |= libc::MS_NOATIME,
+            MntOps::DirATime(false) => flags |= libc::MS_NODIRATIME,
+            MntOps::RelAtime(true) => flags |= libc::MS_RELATIME,
+            MntOps::StrictATime => flags |= libc::MS_STRICTATIME,
+            MntOps::LazyTime(true) => flags |= libc::MS_LAZYTIME,
+            MntOps::Extra(ref s) => extra.push(s.clone()),
+            _ => {}
+        }
+    }
+
+    (flags as libc::c_ulong, extra.join(","))
+}
+
+/// The options of `umount2(2)`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct UnmountFlags {
+    /// `MNT_FORCE`: force unmount even if busy.
+    pub force: bool,
+    /// `MNT_DETACH`: lazy unmount, detach now, clean up when no longer busy.
+    pub detach: bool,
+    /// `MNT_EXPIRE`: mark the mount point as expiring.
+    pub expire: bool,
+    /// `UMOUNT_NOFOLLOW`: don't follow `target` if it's a symlink.
+    pub no_follow: bool,
+}
+
+/// Unmount the mount point at `target` with the given `umount2(2)` options.
+pub fn unmount<P: AsRef<Path>>(target: P, flags: UnmountFlags) -> io::Result<()> {
+    let target = path_to_cstring(target.as_ref())?;
+    let mut raw = 0;
+
+    if flags.force {
+        raw |= libc::MNT_FORCE;
+    }
+    if flags.detach {
+        raw |= libc::MNT_DETACH;
+    }
+    if flags.expire {
+        raw |= libc::MNT_EXPIRE;
+    }
+    if flags.no_follow {
+        raw |= libc::UMOUNT_NOFOLLOW;
+    }
+
+    let ret = unsafe { libc::umount2(target.as_ptr(), raw) };
+
+    if ret == 0 {
+        Ok(())
+    } else {
+        Err(io::Error::last_os_error())
+    }
+}
+
+fn path_to_cstring(path: &Path) -> io::Result<CString> {
+    CString::new(path.as_os_str().as_bytes()).map_err(invalid_input)
+}
+
+fn invalid_input<E: Into<Box<dyn Error + Send + Sync>>>(err: E) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidInput, err)
+}
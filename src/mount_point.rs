@@ -0,0 +1,200 @@
+use std::io;
+use std::path::PathBuf;
+
+/// A mount point view common to every supported platform: enough to answer
+/// "what's mounted where" on systems that don't expose the full set of
+/// mountinfo fields. The Linux backend can always recover the richer
+/// `MountEntry` by reading `/proc/self/mountinfo` directly.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MountPoint {
+    /// where the filesystem is mounted.
+    pub mount_point: PathBuf,
+    /// the device, share or other source being mounted.
+    pub source: String,
+    /// the filesystem type.
+    pub filesystem: String,
+    /// whether the mount is read-only.
+    pub read_only: bool,
+}
+
+/// The mount points of the current system, in a form available on every
+/// supported platform.
+#[cfg(target_os = "linux")]
+pub fn mount_points() -> io::Result<Vec<MountPoint>> {
+    use mntops::MntOps;
+    use process::self_mountinfo;
+
+    Ok(self_mountinfo()?
+        .filter_map(Result::ok)
+        .map(|entry| MountPoint {
+            read_only: entry.mount_opts.contains(&MntOps::Write(false)),
+            mount_point: entry.mount_point,
+            source: entry.mount_source,
+            filesystem: entry.filesystem,
+        })
+        .collect())
+}
+
+/// The mount points of the current system, in a form available on every
+/// supported platform.
+#[cfg(any(
+    target_os = "macos",
+    target_os = "ios",
+    target_os = "freebsd",
+    target_os = "dragonfly",
+    target_os = "openbsd",
+    target_os = "netbsd"
+))]
+pub fn mount_points() -> io::Result<Vec<MountPoint>> {
+    use std::ffi::CStr;
+    use std::ptr;
+    use std::slice;
+
+    use libc::{self, statfs};
+
+    unsafe {
+        let mut buf: *mut statfs = ptr::null_mut();
+        let n = libc::getmntinfo(&mut buf, libc::MNT_NOWAIT);
+
+        if n < 0 {
+            return Err(io::Error::last_os_error());
+        }
+
+        Ok(slice::from_raw_parts(buf, n as usize)
+            .iter()
+            .map(|fs| MountPoint {
+                mount_point: PathBuf::from(
+                    CStr::from_ptr(fs.f_mntonname.as_ptr()).to_string_lossy().into_owned(),
+                ),
+                source: CStr::from_ptr(fs.f_mntfromname.as_ptr())
+                    .to_string_lossy()
+                    .into_owned(),
+                filesystem: CStr::from_ptr(fs.f_fstypename.as_ptr())
+                    .to_string_lossy()
+                    .into_owned(),
+                read_only: fs.f_flags & (libc::MNT_RDONLY as u32) != 0,
+            })
+            .collect())
+    }
+}
+
+/// The mount points of the current system, in a form available on every
+/// supported platform.
+///
+/// Windows has no single mount table: every logical drive and every volume
+/// mount point under an NTFS directory is enumerated instead.
+#[cfg(windows)]
+pub fn mount_points() -> io::Result<Vec<MountPoint>> {
+    use winapi::um::winbase::GetLogicalDrives;
+
+    let mut mounts = vec![];
+    let drives = unsafe { GetLogicalDrives() };
+
+    for letter in 0..26 {
+        if drives & (1 << letter) == 0 {
+            continue;
+        }
+
+        let root = format!("{}:\\", (b'A' + letter) as char);
+
+        windows::push_volume_info(&root, &mut mounts);
+        windows::push_volume_mount_points(&root, &mut mounts);
+    }
+
+    Ok(mounts)
+}
+
+#[cfg(windows)]
+mod windows {
+    use std::ffi::OsString;
+    use std::os::windows::ffi::OsStrExt;
+    use std::path::PathBuf;
+    use std::ptr;
+
+    use winapi::um::fileapi::{
+        FindFirstVolumeMountPointW, FindNextVolumeMountPointW, FindVolumeMountPointClose,
+        GetVolumeInformationW,
+    };
+    use winapi::um::handleapi::INVALID_HANDLE_VALUE;
+    use winapi::um::winnt::FILE_READ_ONLY_VOLUME;
+
+    use super::MountPoint;
+
+    /// `MAX_PATH`, as defined by the Windows API.
+    const MAX_PATH: usize = 260;
+
+    fn to_wide(s: &str) -> Vec<u16> {
+        OsString::from(s).encode_wide().chain(Some(0)).collect()
+    }
+
+    fn from_wide(buf: &[u16]) -> String {
+        let end = buf.iter().position(|&c| c == 0).unwrap_or(buf.len());
+
+        String::from_utf16_lossy(&buf[..end])
+    }
+
+    /// Look up the filesystem and read-only flag of the volume mounted at
+    /// `path` (a drive root or a volume mount point directory) and push it
+    /// onto `mounts`, if it can be queried.
+    pub fn push_volume_info(path: &str, mounts: &mut Vec<MountPoint>) {
+        let wide = to_wide(path);
+        let mut fs_name = [0u16; 32];
+        let mut flags = 0u32;
+
+        let ok = unsafe {
+            GetVolumeInformationW(
+                wide.as_ptr(),
+                ptr::null_mut(),
+                0,
+                ptr::null_mut(),
+                ptr::null_mut(),
+                &mut flags,
+                fs_name.as_mut_ptr(),
+                fs_name.len() as u32,
+            )
+        };
+
+        if ok == 0 {
+            return;
+        }
+
+        mounts.push(MountPoint {
+            mount_point: PathBuf::from(path),
+            source: path.to_owned(),
+            filesystem: from_wide(&fs_name),
+            read_only: flags & FILE_READ_ONLY_VOLUME != 0,
+        });
+    }
+
+    /// Walk every volume mount point (an NTFS junction-like directory that
+    /// another volume is grafted onto) found directly under `root`.
+    pub fn push_volume_mount_points(root: &str, mounts: &mut Vec<MountPoint>) {
+        let root_wide = to_wide(root);
+        let mut name = [0u16; MAX_PATH];
+
+        let handle = unsafe {
+            FindFirstVolumeMountPointW(root_wide.as_ptr(), name.as_mut_ptr(), name.len() as u32)
+        };
+
+        if handle == INVALID_HANDLE_VALUE {
+            return;
+        }
+
+        loop {
+            let mount_point = format!("{}{}", root, from_wide(&name));
+
+            push_volume_info(&mount_point, mounts);
+
+            let ok =
+                unsafe { FindNextVolumeMountPointW(handle, name.as_mut_ptr(), name.len() as u32) };
+
+            if ok == 0 {
+                break;
+            }
+        }
+
+        unsafe {
+            FindVolumeMountPointClose(handle);
+        }
+    }
+}
@@ -27,6 +27,80 @@ pub fn parse_mountinfo<R: Read>(r: R) -> impl Iterator<Item = Result<MountEntry,
         .map(|line| line.map_err(|err| LineError::IoError(err.kind()))?.parse())
 }
 
+const PROC_SELF_MOUNTS: &'static str = "/proc/self/mounts";
+
+/// Returns the mount points in the current process mount namespace, from
+/// the simpler `/proc/self/mounts` format.
+pub fn self_mounts() -> io::Result<impl Iterator<Item = Result<MountEntry, LineError>>> {
+    File::open(PROC_SELF_MOUNTS).map(parse_mounts)
+}
+
+/// Returns the mount points in the given process mount namespace, from the
+/// simpler `/proc/[pid]/mounts` format.
+pub fn proc_mounts(pid: u32) -> io::Result<impl Iterator<Item = Result<MountEntry, LineError>>> {
+    File::open(format!("/proc/{}/mounts", pid)).map(parse_mounts)
+}
+
+/// Parse the mount points from buffer, in the 6-field fstab-style format
+/// used by `/proc/mounts` (spec, file, vfstype, mntops, freq, passno),
+/// rather than the 11-field mountinfo format `parse_mountinfo` understands.
+///
+/// The mountinfo-only fields of the returned `MountEntry` (`mount_id`,
+/// `parent_id`, `dev_major`, `dev_minor`, `tags`, `super_opts`) are left
+/// zeroed or empty, and `root` is set to `/`, since this format doesn't
+/// carry them.
+pub fn parse_mounts<R: Read>(r: R) -> impl Iterator<Item = Result<MountEntry, LineError>> {
+    let r = BufReader::new(r);
+
+    r.lines()
+        .map(|line| parse_mounts_line(&line.map_err(|err| LineError::IoError(err.kind()))?))
+}
+
+/// /dev/sda2 / ext4 rw,relatime 0 1
+/// (1)       (2)(3)    (4)      (5)(6)
+fn parse_mounts_line(line: &str) -> Result<MountEntry, LineError> {
+    let line = line.trim();
+    let mut tokens = line
+        .split_terminator(|s: char| s == ' ' || s == '\t')
+        .filter(|s| s != &"");
+
+    let mount_source = tokens.next().ok_or(LineError::MissingSpec)?.to_owned();
+    let mount_point = tokens.next().ok_or(LineError::MissingFile)?.into();
+    let filesystem = tokens
+        .next()
+        .ok_or(LineError::MissingVfstype)?
+        .to_owned();
+    let mount_opts = tokens
+        .next()
+        .ok_or(LineError::MissingMntops)?
+        .split_terminator(',')
+        .map(|s| s.parse())
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let freq = tokens.next().ok_or(LineError::MissingFreq)?;
+    freq.parse::<u32>()
+        .map_err(|_| LineError::InvalidFreq(freq.to_owned()))?;
+
+    let passno = tokens.next().ok_or(LineError::MissingPassno)?;
+    passno
+        .parse::<u32>()
+        .map_err(|_| LineError::InvalidPassno(passno.to_owned()))?;
+
+    Ok(MountEntry {
+        mount_id: 0,
+        parent_id: 0,
+        dev_major: 0,
+        dev_minor: 0,
+        root: PathBuf::from("/"),
+        mount_point,
+        mount_opts,
+        tags: vec![],
+        filesystem,
+        mount_source,
+        super_opts: vec![],
+    })
+}
+
 /// The mount points in the process's mount namespace
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct MountEntry {
@@ -154,6 +228,72 @@ impl FromStr for MountEntry {
     }
 }
 
+/// A peer group ID, as used by the `shared:`/`master:`/`propagate_from:` tags.
+pub type PeerGroup = u32;
+
+/// The propagation type of a mount, derived from its `shared:`, `master:`,
+/// `propagate_from:` and `unbindable` tags, mirroring the kernel's peer-group
+/// model for mount propagation.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Propagation {
+    /// Mounted in a shared peer group: mount/unmount events propagate to and
+    /// from every other member of that group.
+    Shared(PeerGroup),
+    /// A slave of a shared peer group: mount/unmount events propagate into
+    /// this mount, but never back out to its master.
+    Slave {
+        /// the peer group this mount receives propagation from.
+        master: PeerGroup,
+        /// the peer group this slave's master itself propagates from, if any.
+        propagate_from: Option<PeerGroup>,
+    },
+    /// A private mount: no propagation in either direction.
+    Private,
+    /// An unbindable mount: behaves like a private mount, and additionally
+    /// cannot be bind-mounted.
+    Unbindable,
+}
+
+impl MountEntry {
+    /// The propagation type of this mount, derived from its tags.
+    pub fn propagation(&self) -> Propagation {
+        if self.tags.iter().any(|(name, _)| name == "unbindable") {
+            return Propagation::Unbindable;
+        }
+
+        let shared = self.tags
+            .iter()
+            .find(|(name, _)| name == "shared")
+            .and_then(|(_, value)| value.as_ref())
+            .and_then(|value| value.parse().ok());
+
+        if let Some(peer_group) = shared {
+            return Propagation::Shared(peer_group);
+        }
+
+        let master = self.tags
+            .iter()
+            .find(|(name, _)| name == "master")
+            .and_then(|(_, value)| value.as_ref())
+            .and_then(|value| value.parse().ok());
+
+        if let Some(master) = master {
+            let propagate_from = self.tags
+                .iter()
+                .find(|(name, _)| name == "propagate_from")
+                .and_then(|(_, value)| value.as_ref())
+                .and_then(|value| value.parse().ok());
+
+            return Propagation::Slave {
+                master,
+                propagate_from,
+            };
+        }
+
+        Propagation::Private
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -215,4 +355,100 @@ mod tests {
 
         assert!(entries.next().is_none());
     }
+
+    #[test]
+    fn test_propagation() {
+        let mut entry = MountEntry {
+            mount_id: 21,
+            parent_id: 26,
+            dev_major: 0,
+            dev_minor: 20,
+            root: "/".into(),
+            mount_point: "/sys".into(),
+            mount_opts: vec![],
+            tags: vec![("shared".to_owned(), Some("7".to_owned()))],
+            filesystem: "sysfs".to_owned(),
+            mount_source: "sysfs".to_owned(),
+            super_opts: vec![],
+        };
+
+        assert_eq!(entry.propagation(), Propagation::Shared(7));
+
+        entry.tags = vec![
+            ("master".to_owned(), Some("1".to_owned())),
+            ("propagate_from".to_owned(), Some("2".to_owned())),
+        ];
+        assert_eq!(
+            entry.propagation(),
+            Propagation::Slave {
+                master: 1,
+                propagate_from: Some(2),
+            }
+        );
+
+        entry.tags = vec![];
+        assert_eq!(entry.propagation(), Propagation::Private);
+
+        entry.tags = vec![("unbindable".to_owned(), None)];
+        assert_eq!(entry.propagation(), Propagation::Unbindable);
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn test_self_mounts() {
+        assert!(self_mounts().is_ok());
+    }
+
+    #[test]
+    fn test_parse_mounts() {
+        let s = b"sysfs /sys sysfs rw,nosuid,nodev,noexec,relatime 0 0
+/dev/sda2 / ext4 rw,relatime,data=ordered 0 1";
+
+        let mut entries = parse_mounts(&s[..]);
+
+        assert_eq!(
+            MountEntry {
+                mount_id: 0,
+                parent_id: 0,
+                dev_major: 0,
+                dev_minor: 0,
+                root: "/".into(),
+                mount_point: "/sys".into(),
+                mount_opts: vec![
+                    MntOps::Write(true),
+                    MntOps::Suid(false),
+                    MntOps::Dev(false),
+                    MntOps::Exec(false),
+                    MntOps::RelAtime(true),
+                ],
+                tags: vec![],
+                filesystem: "sysfs".to_owned(),
+                mount_source: "sysfs".to_owned(),
+                super_opts: vec![],
+            },
+            entries.next().unwrap().unwrap()
+        );
+        assert_eq!(
+            MountEntry {
+                mount_id: 0,
+                parent_id: 0,
+                dev_major: 0,
+                dev_minor: 0,
+                root: "/".into(),
+                mount_point: "/".into(),
+                mount_opts: vec![
+                    MntOps::Write(true),
+                    MntOps::RelAtime(true),
+                    MntOps::Extra("data=ordered".to_owned()),
+                ],
+                tags: vec![],
+                filesystem: "ext4".to_owned(),
+                mount_source: "/dev/sda2".to_owned(),
+                super_opts: vec![],
+            },
+            entries.next().unwrap().unwrap()
+        );
+
+        assert!(entries.next().is_none());
+    }
 }
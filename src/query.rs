@@ -0,0 +1,121 @@
+use std::io;
+use std::path::Path;
+
+use process::{self_mountinfo, MountEntry};
+
+/// Whether `path` is itself a mount point in the current namespace.
+pub fn is_target_mounted<P: AsRef<Path>>(path: P) -> io::Result<bool> {
+    Ok(find_by_mount_point(path)?.is_some())
+}
+
+/// Whether `source` is the source of any mount in the current namespace,
+/// e.g. a device or bind-mount source already in use.
+pub fn is_source_mounted(source: &str) -> io::Result<bool> {
+    Ok(self_mountinfo()?
+        .filter_map(Result::ok)
+        .any(|entry| entry.mount_source == source))
+}
+
+/// The entry whose `mount_point` is exactly `path`, in the current namespace.
+pub fn find_by_mount_point<P: AsRef<Path>>(path: P) -> io::Result<Option<MountEntry>> {
+    let path = path.as_ref();
+
+    Ok(self_mountinfo()?
+        .filter_map(Result::ok)
+        .find(|entry| entry.mount_point == path))
+}
+
+/// All entries in the current namespace with the given filesystem type.
+pub fn filter_by_filesystem(filesystem: &str) -> io::Result<Vec<MountEntry>> {
+    Ok(self_mountinfo()?
+        .filter_map(Result::ok)
+        .filter(|entry| entry.filesystem == filesystem)
+        .collect())
+}
+
+/// Whether `path` is a mount point among the given entries, without
+/// re-reading the mount table.
+pub fn is_target_mounted_in<'a, I, P>(entries: I, path: P) -> bool
+where
+    I: IntoIterator<Item = &'a MountEntry>,
+    P: AsRef<Path>,
+{
+    find_by_mount_point_in(entries, path).is_some()
+}
+
+/// Whether `source` is the source of any of the given entries, without
+/// re-reading the mount table.
+pub fn is_source_mounted_in<'a, I>(entries: I, source: &str) -> bool
+where
+    I: IntoIterator<Item = &'a MountEntry>,
+{
+    entries.into_iter().any(|entry| entry.mount_source == source)
+}
+
+/// The entry whose `mount_point` is exactly `path`, among the given entries.
+pub fn find_by_mount_point_in<'a, I, P>(entries: I, path: P) -> Option<&'a MountEntry>
+where
+    I: IntoIterator<Item = &'a MountEntry>,
+    P: AsRef<Path>,
+{
+    let path = path.as_ref();
+
+    entries.into_iter().find(|entry| entry.mount_point == path)
+}
+
+/// All of the given entries with the given filesystem type.
+pub fn filter_by_filesystem_in<'a, I>(entries: I, filesystem: &str) -> Vec<&'a MountEntry>
+where
+    I: IntoIterator<Item = &'a MountEntry>,
+{
+    entries
+        .into_iter()
+        .filter(|entry| entry.filesystem == filesystem)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use process::parse_mountinfo;
+
+    fn entries(s: &str) -> Vec<MountEntry> {
+        parse_mountinfo(s.as_bytes())
+            .map(Result::unwrap)
+            .collect()
+    }
+
+    #[test]
+    fn test_find_by_mount_point_in() {
+        let s = "21 26 0:20 / /sys rw,nosuid,nodev,noexec,relatime shared:7 - sysfs sysfs rw
+26 0 8:2 / / rw,relatime - ext4 /dev/sda2 rw,data=ordered";
+        let entries = entries(s);
+
+        assert!(is_target_mounted_in(&entries, "/sys"));
+        assert!(!is_target_mounted_in(&entries, "/proc"));
+        assert_eq!(
+            find_by_mount_point_in(&entries, "/").unwrap().mount_id,
+            26
+        );
+    }
+
+    #[test]
+    fn test_is_source_mounted_in() {
+        let s = "26 0 8:2 / / rw,relatime - ext4 /dev/sda2 rw,data=ordered";
+        let entries = entries(s);
+
+        assert!(is_source_mounted_in(&entries, "/dev/sda2"));
+        assert!(!is_source_mounted_in(&entries, "/dev/sda1"));
+    }
+
+    #[test]
+    fn test_filter_by_filesystem_in() {
+        let s = "21 26 0:20 / /sys rw,nosuid,nodev,noexec,relatime shared:7 - sysfs sysfs rw
+26 0 8:2 / / rw,relatime - ext4 /dev/sda2 rw,data=ordered";
+        let entries = entries(s);
+
+        assert_eq!(filter_by_filesystem_in(&entries, "sysfs").len(), 1);
+        assert_eq!(filter_by_filesystem_in(&entries, "ext4").len(), 1);
+        assert!(filter_by_filesystem_in(&entries, "tmpfs").is_empty());
+    }
+}
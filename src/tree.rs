@@ -0,0 +1,184 @@
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+
+use process::{MountEntry, MountId};
+
+/// The parent/child hierarchy of a set of `MountEntry`, reconstructed from
+/// their `mount_id` / `parent_id` links the way container runtimes walk
+/// `/proc/self/mountinfo` to reason about what is mounted under what.
+#[derive(Clone, Debug)]
+pub struct MountTree {
+    entries: HashMap<MountId, MountEntry>,
+    children: HashMap<MountId, Vec<MountId>>,
+    roots: Vec<MountId>,
+}
+
+impl MountTree {
+    /// Consume an iterator of parsed entries and build the tree.
+    ///
+    /// An entry is a root if its `parent_id` has no matching `mount_id` in
+    /// the set (the parent mount lives outside this namespace), or if it
+    /// points back to itself.
+    ///
+    /// `mount_id` is only guaranteed unique within a single mount namespace,
+    /// so mixing entries read from more than one namespace (or otherwise
+    /// containing duplicate IDs) silently collapses onto the last entry
+    /// seen for that ID, with its children reparented onto the survivor.
+    /// Build one `MountTree` per namespace if that matters to the caller.
+    pub fn from_entries<I: IntoIterator<Item = MountEntry>>(entries: I) -> MountTree {
+        let entries: HashMap<MountId, MountEntry> = entries
+            .into_iter()
+            .map(|entry| (entry.mount_id, entry))
+            .collect();
+
+        let mut children: HashMap<MountId, Vec<MountId>> = HashMap::new();
+        let mut roots = vec![];
+
+        for entry in entries.values() {
+            if entry.parent_id != entry.mount_id && entries.contains_key(&entry.parent_id) {
+                children
+                    .entry(entry.parent_id)
+                    .or_default()
+                    .push(entry.mount_id);
+            } else {
+                roots.push(entry.mount_id);
+            }
+        }
+
+        MountTree {
+            entries,
+            children,
+            roots,
+        }
+    }
+
+    /// The top-level mounts, i.e. those with no parent inside this tree.
+    pub fn roots(&self) -> &[MountId] {
+        &self.roots
+    }
+
+    /// The entry for a given mount ID, if it is part of this tree.
+    pub fn get(&self, mount_id: MountId) -> Option<&MountEntry> {
+        self.entries.get(&mount_id)
+    }
+
+    /// The direct children of a mount, or an empty slice if it has none.
+    pub fn children(&self, mount_id: MountId) -> &[MountId] {
+        self.children
+            .get(&mount_id)
+            .map(Vec::as_slice)
+            .unwrap_or(&[])
+    }
+
+    /// The entry for the parent of a mount, if any.
+    pub fn parent(&self, mount_id: MountId) -> Option<&MountEntry> {
+        self.entries
+            .get(&mount_id)
+            .and_then(|entry| self.entries.get(&entry.parent_id))
+    }
+
+    /// A depth-first iterator over all descendants of a mount, guarding
+    /// against cycles in case of malformed or adversarial input.
+    pub fn descendants(&self, mount_id: MountId) -> Descendants<'_> {
+        Descendants {
+            tree: self,
+            stack: self.children(mount_id).to_vec(),
+            visited: HashSet::new(),
+        }
+    }
+
+    /// The entry whose `mount_point` is the longest prefix of `path`, i.e.
+    /// the mount that is actually effective for that path.
+    ///
+    /// When two mounts share the exact same `mount_point` (an overmount,
+    /// common in containers), the one with the larger `mount_id` wins, since
+    /// mount IDs are assigned in mount order and the later mount is the one
+    /// shadowing the others.
+    pub fn find_covering<P: AsRef<Path>>(&self, path: P) -> Option<&MountEntry> {
+        let path = path.as_ref();
+
+        self.entries
+            .values()
+            .filter(|entry| path.starts_with(&entry.mount_point))
+            .max_by_key(|entry| (entry.mount_point.as_os_str().len(), entry.mount_id))
+    }
+}
+
+/// Depth-first traversal over a `MountTree`'s descendants.
+pub struct Descendants<'a> {
+    tree: &'a MountTree,
+    stack: Vec<MountId>,
+    visited: HashSet<MountId>,
+}
+
+impl<'a> Iterator for Descendants<'a> {
+    type Item = MountId;
+
+    fn next(&mut self) -> Option<MountId> {
+        while let Some(id) = self.stack.pop() {
+            if !self.visited.insert(id) {
+                continue;
+            }
+
+            self.stack.extend(self.tree.children(id));
+
+            return Some(id);
+        }
+
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use process::parse_mountinfo;
+
+    fn entries(s: &str) -> Vec<MountEntry> {
+        parse_mountinfo(s.as_bytes())
+            .map(Result::unwrap)
+            .collect()
+    }
+
+    #[test]
+    fn test_single_root() {
+        let s = "21 26 0:20 / /sys rw,nosuid,nodev,noexec,relatime shared:7 - sysfs sysfs rw
+26 0 8:2 / / rw,relatime - ext4 /dev/sda2 rw,data=ordered";
+
+        let tree = MountTree::from_entries(entries(s));
+
+        assert_eq!(tree.roots(), &[26]);
+        assert_eq!(tree.children(26), &[21]);
+        assert!(tree.children(21).is_empty());
+        assert_eq!(tree.parent(21).unwrap().mount_id, 26);
+        assert!(tree.parent(26).is_none());
+        assert_eq!(tree.descendants(26).collect::<Vec<_>>(), vec![21]);
+    }
+
+    #[test]
+    fn test_find_covering() {
+        let s = "21 26 0:20 / /sys rw,nosuid,nodev,noexec,relatime shared:7 - sysfs sysfs rw
+26 0 8:2 / / rw,relatime - ext4 /dev/sda2 rw,data=ordered";
+
+        let tree = MountTree::from_entries(entries(s));
+
+        assert_eq!(
+            tree.find_covering("/sys/fs/cgroup").unwrap().mount_id,
+            21
+        );
+        assert_eq!(tree.find_covering("/home").unwrap().mount_id, 26);
+    }
+
+    #[test]
+    fn test_find_covering_overmount() {
+        // two mounts stacked on the exact same mount point; the later one
+        // (higher mount_id) shadows the earlier one.
+        let s = "21 26 0:20 / /mnt rw - tmpfs tmpfs1 rw
+22 26 0:21 / /mnt rw - tmpfs tmpfs2 rw
+26 0 8:2 / / rw,relatime - ext4 /dev/sda2 rw,data=ordered";
+
+        let tree = MountTree::from_entries(entries(s));
+
+        assert_eq!(tree.find_covering("/mnt").unwrap().mount_id, 22);
+    }
+}